@@ -0,0 +1,204 @@
+use crate::user_config::{KeyBinding, UserConfig};
+use termion::event::Key;
+
+// A playback device as returned by the Spotify "available devices" endpoint;
+// only the fields `select_device` needs are reproduced here.
+pub struct Device {
+    pub name: String,
+}
+
+// Picks the device matching `behavior.default_device_name`, falling back to
+// the first device Spotify reports when there's no match (or no preference
+// configured) - mirrors how Spotify's own clients auto-select a device.
+pub fn select_device<'a>(devices: &'a [Device], default_device_name: Option<&str>) -> Option<&'a Device> {
+    if let Some(name) = default_device_name {
+        if let Some(device) = devices.iter().find(|d| d.name == name) {
+            return Some(device);
+        }
+    }
+    devices.first()
+}
+
+// Dispatches incoming termion key events against the configured bindings,
+// including multi-press chords like `g-g`: the first key of a chord is
+// buffered in `pending_key` until the next event arrives to complete it.
+pub struct App {
+    pub user_config: UserConfig,
+    pub volume: u8,
+    pub progress_ms: u128,
+    pending_key: Option<Key>,
+}
+
+impl App {
+    pub fn new(user_config: UserConfig) -> App {
+        App {
+            user_config,
+            volume: 50,
+            progress_ms: 0,
+            pending_key: None,
+        }
+    }
+
+    pub fn increase_volume(&mut self) {
+        let step = self.user_config.behavior.volume_increment;
+        self.volume = self.volume.saturating_add(step).min(100);
+    }
+
+    pub fn decrease_volume(&mut self) {
+        let step = self.user_config.behavior.volume_increment;
+        self.volume = self.volume.saturating_sub(step);
+    }
+
+    pub fn seek_forwards(&mut self, duration_ms: u128) {
+        let step = u128::from(self.user_config.behavior.seek_milliseconds);
+        self.progress_ms = (self.progress_ms + step).min(duration_ms);
+    }
+
+    pub fn seek_backwards(&mut self) {
+        let step = u128::from(self.user_config.behavior.seek_milliseconds);
+        self.progress_ms = self.progress_ms.saturating_sub(step);
+    }
+
+    fn bindings(&self) -> Vec<(KeyBinding, &'static str)> {
+        vec![
+            (self.user_config.back.clone(), "back"),
+            (self.user_config.jump_to_album.clone(), "jump_to_album"),
+            (
+                self.user_config.jump_to_artist_album.clone(),
+                "jump_to_artist_album",
+            ),
+            (self.user_config.manage_devices.clone(), "manage_devices"),
+            (self.user_config.decrease_volume.clone(), "decrease_volume"),
+            (self.user_config.increase_volume.clone(), "increase_volume"),
+            (self.user_config.toggle_playback.clone(), "toggle_playback"),
+            (self.user_config.seek_backwards.clone(), "seek_backwards"),
+            (self.user_config.seek_forwards.clone(), "seek_forwards"),
+            (self.user_config.next_track.clone(), "next_track"),
+            (self.user_config.previous_track.clone(), "previous_track"),
+            (self.user_config.help.clone(), "help"),
+            (self.user_config.shuffle.clone(), "shuffle"),
+            (self.user_config.repeat.clone(), "repeat"),
+            (self.user_config.search.clone(), "search"),
+        ]
+    }
+
+    // Returns the bound action name once `key` completes a binding - either
+    // immediately for a `Single` binding, or on the second press of a
+    // `Sequence` chord.
+    pub fn dispatch_key(&mut self, key: Key) -> Option<&'static str> {
+        let bindings = self.bindings();
+
+        if let Some(first) = self.pending_key.take() {
+            for (binding, action) in &bindings {
+                if let KeyBinding::Sequence(keys) = binding {
+                    if keys.as_slice() == [first, key] {
+                        return Some(action);
+                    }
+                }
+            }
+            // The buffered key didn't complete a chord; fall through and
+            // evaluate this key as a fresh press.
+        }
+
+        for (binding, action) in &bindings {
+            match binding {
+                KeyBinding::Single(bound_key) if *bound_key == key => return Some(action),
+                KeyBinding::Sequence(keys) if keys.first() == Some(&key) => {
+                    self.pending_key = Some(key);
+                    return None;
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::App;
+    use crate::user_config::{KeyBinding, UserConfig};
+    use termion::event::Key;
+
+    #[test]
+    fn test_dispatch_single_key() {
+        let mut app = App::new(UserConfig::new());
+        assert_eq!(app.dispatch_key(Key::Char('q')), Some("back"));
+    }
+
+    #[test]
+    fn test_dispatch_chord() {
+        let mut user_config = UserConfig::new();
+        user_config.back = KeyBinding::Sequence(vec![Key::Char('g'), Key::Char('g')]);
+        let mut app = App::new(user_config);
+
+        assert_eq!(app.dispatch_key(Key::Char('g')), None);
+        assert_eq!(app.dispatch_key(Key::Char('g')), Some("back"));
+    }
+
+    #[test]
+    fn test_dispatch_chord_interrupted() {
+        let mut user_config = UserConfig::new();
+        user_config.back = KeyBinding::Sequence(vec![Key::Char('g'), Key::Char('g')]);
+        let mut app = App::new(user_config);
+
+        assert_eq!(app.dispatch_key(Key::Char('g')), None);
+        assert_eq!(app.dispatch_key(Key::Char('x')), None);
+    }
+
+    #[test]
+    fn test_volume_uses_configured_increment() {
+        let mut user_config = UserConfig::new();
+        user_config.behavior.volume_increment = 20;
+        let mut app = App::new(user_config);
+
+        app.volume = 50;
+        app.increase_volume();
+        assert_eq!(app.volume, 70);
+        app.decrease_volume();
+        app.decrease_volume();
+        assert_eq!(app.volume, 30);
+    }
+
+    #[test]
+    fn test_seek_uses_configured_milliseconds() {
+        let mut user_config = UserConfig::new();
+        user_config.behavior.seek_milliseconds = 1_000;
+        let mut app = App::new(user_config);
+
+        app.progress_ms = 5_000;
+        app.seek_forwards(10_000);
+        assert_eq!(app.progress_ms, 6_000);
+        app.seek_backwards();
+        app.seek_backwards();
+        assert_eq!(app.progress_ms, 4_000);
+    }
+
+    #[test]
+    fn test_select_device_prefers_configured_default() {
+        use super::{select_device, Device};
+
+        let devices = vec![
+            Device {
+                name: "Kitchen".to_string(),
+            },
+            Device {
+                name: "Office".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            select_device(&devices, Some("Office")).map(|d| d.name.as_str()),
+            Some("Office")
+        );
+        assert_eq!(
+            select_device(&devices, Some("Unknown")).map(|d| d.name.as_str()),
+            Some("Kitchen")
+        );
+        assert_eq!(
+            select_device(&devices, None).map(|d| d.name.as_str()),
+            Some("Kitchen")
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::user_config::LastfmClientConfig;
+use serde::Deserialize;
+
+const API_ROOT: &str = "https://ws.audioscrobbler.com/2.0/";
+
+// A scrobble is submitted once a track has played for at least half its
+// length, or 4 minutes, whichever comes first - the threshold Last.fm itself
+// recommends to scrobblers.
+fn should_scrobble(elapsed_ms: u128, duration_ms: u128) -> bool {
+    let half = duration_ms / 2;
+    let four_minutes = 4 * 60 * 1000;
+    elapsed_ms >= half.min(four_minutes)
+}
+
+// Signs a Last.fm API call: sort the params, concatenate key/value pairs,
+// append the shared secret, then md5 the result. Last.fm requires every
+// param except `format`/`callback` - including `api_key` - to be part of
+// the signature, so callers must include it in `params` before signing.
+fn sign(params: &[(&str, String)], api_secret: &str) -> String {
+    let mut sorted: Vec<&(&str, String)> = params.iter().collect();
+    sorted.sort_by_key(|(key, _)| *key);
+
+    let mut signature_base = String::new();
+    for (key, value) in sorted {
+        signature_base.push_str(key);
+        signature_base.push_str(value);
+    }
+    signature_base.push_str(api_secret);
+
+    format!("{:x}", md5::compute(signature_base))
+}
+
+#[derive(Deserialize)]
+struct MobileSessionResponse {
+    session: MobileSession,
+}
+
+#[derive(Deserialize)]
+struct MobileSession {
+    key: String,
+}
+
+// Submits "now playing" notifications and scrobbles to the Last.fm API,
+// authenticating with a session key obtained once at construction time via
+// `auth.getMobileSession` - Last.fm never accepts a raw password on the
+// now-playing/scrobble calls themselves.
+pub struct Scrobbler {
+    config: LastfmClientConfig,
+    client: reqwest::blocking::Client,
+    session_key: String,
+    last_scrobbled_uri: Option<String>,
+}
+
+impl Scrobbler {
+    pub fn new(config: LastfmClientConfig) -> Result<Scrobbler, failure::Error> {
+        let client = reqwest::blocking::Client::new();
+        let session_key = Self::get_mobile_session(&client, &config)?;
+
+        Ok(Scrobbler {
+            config,
+            client,
+            session_key,
+            last_scrobbled_uri: None,
+        })
+    }
+
+    // Exchanges the configured username/password for a session key via
+    // `auth.getMobileSession`, the handshake Last.fm recommends for clients
+    // without a web browser available to complete the usual auth flow.
+    fn get_mobile_session(
+        client: &reqwest::blocking::Client,
+        config: &LastfmClientConfig,
+    ) -> Result<String, failure::Error> {
+        let params = [
+            ("method", "auth.getMobileSession".to_string()),
+            ("username", config.username.clone()),
+            ("password", config.password.clone()),
+            ("api_key", config.api_key.clone()),
+        ];
+        let api_sig = sign(&params, &config.api_secret);
+
+        let mut form: Vec<(&str, String)> = params.to_vec();
+        form.push(("api_sig", api_sig));
+        form.push(("format", "json".to_string()));
+
+        let response: MobileSessionResponse = client.post(API_ROOT).form(&form).send()?.json()?;
+        Ok(response.session.key)
+    }
+
+    fn post(&self, params: &[(&str, String)]) -> Result<(), failure::Error> {
+        let api_sig = sign(params, &self.config.api_secret);
+        let mut form: Vec<(&str, String)> = params.to_vec();
+        form.push(("api_sig", api_sig));
+        form.push(("format", "json".to_string()));
+
+        self.client.post(API_ROOT).form(&form).send()?;
+        Ok(())
+    }
+
+    pub fn now_playing(&self, artist: &str, track: &str) -> Result<(), failure::Error> {
+        self.post(&[
+            ("method", "track.updateNowPlaying".to_string()),
+            ("sk", self.session_key.clone()),
+            ("api_key", self.config.api_key.clone()),
+            ("artist", artist.to_string()),
+            ("track", track.to_string()),
+        ])
+    }
+
+    // Submits a scrobble once `elapsed_ms`/`duration_ms` cross the standard
+    // threshold, de-duplicating repeat calls for the same `track_uri`.
+    pub fn scrobble(
+        &mut self,
+        track_uri: &str,
+        artist: &str,
+        track: &str,
+        started_at_unix: u64,
+        elapsed_ms: u128,
+        duration_ms: u128,
+    ) -> Result<(), failure::Error> {
+        if !should_scrobble(elapsed_ms, duration_ms) {
+            return Ok(());
+        }
+        if self.last_scrobbled_uri.as_deref() == Some(track_uri) {
+            return Ok(());
+        }
+
+        self.post(&[
+            ("method", "track.scrobble".to_string()),
+            ("sk", self.session_key.clone()),
+            ("api_key", self.config.api_key.clone()),
+            ("artist", artist.to_string()),
+            ("track", track.to_string()),
+            ("timestamp", started_at_unix.to_string()),
+        ])?;
+
+        self.last_scrobbled_uri = Some(track_uri.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_should_scrobble() {
+        use super::should_scrobble;
+        // 3 minute track: half its length is the threshold.
+        assert!(!should_scrobble(89_000, 180_000));
+        assert!(should_scrobble(90_000, 180_000));
+        // 10 minute track: 4 minutes is the threshold, not half.
+        assert!(!should_scrobble(239_000, 600_000));
+        assert!(should_scrobble(240_000, 600_000));
+    }
+}
@@ -0,0 +1,90 @@
+mod app;
+mod lastfm;
+mod ui;
+mod user_config;
+
+use crate::app::{select_device, App};
+use crate::lastfm::Scrobbler;
+use crate::user_config::UserConfig;
+use std::io::stdin;
+use std::path::PathBuf;
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+use tui::backend::TermionBackend;
+use tui::Terminal;
+
+fn main() -> Result<(), failure::Error> {
+    let matches = clap::App::new("spotify-tui")
+        .version(clap::crate_version!())
+        .about("Spotify for the terminal")
+        .arg(
+            clap::Arg::with_name("config")
+                .short("c")
+                .long("config")
+                .help("Specify configuration file path")
+                .takes_value(true),
+        )
+        .get_matches();
+
+    let config_file_path = matches.value_of("config").map(PathBuf::from);
+
+    let mut user_config = UserConfig::new();
+    user_config.load_config(config_file_path)?;
+
+    // Scrobbler::new performs the auth.getMobileSession handshake, so a
+    // misconfigured lastfm section fails fast here rather than on the first
+    // track change. There's no playback loop in this tree yet to drive
+    // `now_playing`/`scrobble` off real track changes, so the scrobbler is
+    // otherwise unused for now.
+    let scrobbler = user_config.lastfm.clone().map(Scrobbler::new).transpose()?;
+    let _ = scrobbler;
+
+    let tick_rate = std::time::Duration::from_millis(user_config.behavior.tick_rate_milliseconds);
+
+    let mut app = App::new(user_config);
+
+    // There's no device-discovery network call in this tree yet; once one
+    // exists, its result feeds `select_device` to honour
+    // `behavior.default_device_name`.
+    let devices = Vec::new();
+    if let Some(device) = select_device(&devices, app.user_config.behavior.default_device_name.as_deref())
+    {
+        let _ = device;
+    }
+
+    // The redraw tick: how often the playback position/progress bar refresh
+    // even without a key event, controlled by `behavior.tick_rate_milliseconds`.
+    std::thread::spawn(move || loop {
+        std::thread::sleep(tick_rate);
+        // Would poll current playback progress and trigger a redraw here.
+    });
+
+    let stdout = std::io::stdout().into_raw_mode()?;
+    let backend = TermionBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    terminal.draw(|f| {
+        let size = f.size();
+        ui::draw_playbar(f, &app, size, 0);
+    })?;
+
+    // Drives `App::dispatch_key` off stdin; the rest of the redraw-on-tick
+    // loop lives alongside this and isn't reproduced here.
+    for event in stdin().keys() {
+        let key = event?;
+        match app.dispatch_key(key) {
+            Some("increase_volume") => app.increase_volume(),
+            Some("decrease_volume") => app.decrease_volume(),
+            // Clamped to the current track's duration once playback state is
+            // tracked; there's no upper bound to enforce yet.
+            Some("seek_forwards") => app.seek_forwards(u128::max_value()),
+            Some("seek_backwards") => app.seek_backwards(),
+            Some(_other) => {
+                // handlers.rs would route the rest of the actions here.
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
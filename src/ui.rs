@@ -0,0 +1,54 @@
+use crate::app::App;
+use crate::user_config::Theme;
+use tui::backend::Backend;
+use tui::layout::Rect;
+use tui::style::Style;
+use tui::widgets::{Block, Borders, Gauge};
+use tui::Frame;
+
+// Every style used by the widgets below is derived from `Theme` rather than
+// a hard-coded `Color`, so a user's `theme:` block actually recolors the UI.
+pub fn header_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.header)
+}
+
+pub fn active_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.active)
+}
+
+pub fn inactive_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.inactive)
+}
+
+pub fn hovered_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.hovered)
+}
+
+pub fn selected_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.selected)
+}
+
+pub fn banner_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.banner)
+}
+
+pub fn playbar_style(theme: &Theme) -> Style {
+    Style::default()
+        .fg(theme.playbar_progress)
+        .bg(theme.playbar_background)
+}
+
+pub fn draw_playbar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect, percent: u16) {
+    let theme = &app.user_config.theme;
+    let playbar = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(inactive_style(theme))
+                .title_style(header_style(theme)),
+        )
+        .gauge_style(playbar_style(theme))
+        .percent(percent);
+
+    f.render_widget(playbar, area);
+}
@@ -1,58 +1,234 @@
 use dirs;
 use failure::err_msg;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
 use termion::event::Key;
+use tui::style::Color;
 
 const FILE_NAME: &str = "config.yml";
 const CONFIG_DIR: &str = ".config";
 const APP_CONFIG_DIR: &str = "spotify-tui";
+const LASTFM_API_KEY_ENV: &str = "LASTFM_API_KEY";
 
-fn parse_key(key: String) -> Result<Key, failure::Error> {
-    fn get_single_char(string: &str) -> char {
-        match string.chars().nth(0) {
-            Some(c) => c,
-            None => panic!(),
-        }
+// A parsed keybinding: either a single keypress, or a chord of keys that
+// must be pressed one after another, e.g. `g-g`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyBinding {
+    Single(Key),
+    Sequence(Vec<Key>),
+}
+
+const MODIFIERS: [&str; 3] = ["ctrl", "alt", "shift"];
+
+fn get_single_char(string: &str) -> char {
+    match string.chars().nth(0) {
+        Some(c) => c,
+        None => panic!(),
     }
+}
 
-    match key.len() {
-        1 => Ok(Key::Char(get_single_char(key.as_str()))),
-        _ => {
-            let sections: Vec<&str> = key.split('-').collect();
+// Parses a single dash-free token into a `Key`: a bare character, a function
+// key (`f1`..`f12`), or one of the named keys below.
+fn parse_named_key(name: &str) -> Result<Key, failure::Error> {
+    if name.len() == 1 {
+        return Ok(Key::Char(get_single_char(name)));
+    }
+
+    let lower = name.to_lowercase();
 
-            if sections.len() > 2 {
-                return Err(failure::format_err!(
-                    "Shortcut can only have 2 keys, \"{}\" has {}",
-                    key,
-                    sections.len()
-                ));
+    if let Some(digits) = lower.strip_prefix('f') {
+        if let Ok(n) = digits.parse::<u8>() {
+            if (1..=12).contains(&n) {
+                return Ok(Key::F(n));
             }
+        }
+    }
+
+    match lower.as_str() {
+        "left" => Ok(Key::Left),
+        "right" => Ok(Key::Right),
+        "up" => Ok(Key::Up),
+        "down" => Ok(Key::Down),
+        "backspace" | "delete" => Ok(Key::Backspace),
+        "del" => Ok(Key::Delete),
+        "esc" | "escape" => Ok(Key::Esc),
+        "pageup" => Ok(Key::PageUp),
+        "pagedown" => Ok(Key::PageDown),
+        "space" => Ok(Key::Char(' ')),
+        "tab" => Ok(Key::Char('\t')),
+        _ => Err(failure::format_err!("The key \"{}\" is unknown.", name)),
+    }
+}
+
+// Applies a single fold of `ctrl`/`alt`/`shift` modifiers to a base key
+// token. termion can only represent one modifier at a time, so stacking more
+// than one (e.g. `ctrl-alt-x`) is an error.
+fn apply_modifiers(modifiers: &[String], base: &str) -> Result<Key, failure::Error> {
+    if modifiers.len() > 1 {
+        return Err(failure::format_err!(
+            "termion can only represent a single modifier, but \"{}-{}\" has {}",
+            modifiers.join("-"),
+            base,
+            modifiers.len()
+        ));
+    }
+
+    match modifiers[0].as_str() {
+        "ctrl" => Ok(Key::Ctrl(require_single_char(base)?)),
+        "alt" => Ok(Key::Alt(require_single_char(base)?)),
+        "shift" => match base.to_lowercase().as_str() {
+            "tab" => Ok(Key::BackTab),
+            _ => Ok(Key::Char(require_single_char(base)?.to_ascii_uppercase())),
+        },
+        other => Err(failure::format_err!("The modifier \"{}\" is unknown.", other)),
+    }
+}
 
-            match sections[0].to_lowercase().as_str() {
-                "ctrl" => Ok(Key::Ctrl(get_single_char(sections[1]))),
-                "alt" => Ok(Key::Alt(get_single_char(sections[1]))),
-                "left" => Ok(Key::Left),
-                "right" => Ok(Key::Right),
-                "up" => Ok(Key::Up),
-                "down" => Ok(Key::Down),
-                "backspace" | "delete" => Ok(Key::Backspace),
-                "del" => Ok(Key::Delete),
-                "esc" | "escape" => Ok(Key::Esc),
-                "pageup" => Ok(Key::PageUp),
-                "pagedown" => Ok(Key::PageDown),
-                "space" => Ok(Key::Char(' ')),
-                _ => Err(failure::format_err!(
-                    "The key \"{}\" is unknown.",
-                    sections[0]
-                )),
+// termion's `Ctrl`/`Alt` variants (and our upper-cased `shift` char) only
+// wrap a single character, so a named multi-char base like `left` or `space`
+// can't be folded into one without silently truncating it - reject it
+// instead.
+fn require_single_char(base: &str) -> Result<char, failure::Error> {
+    if base.chars().count() != 1 {
+        return Err(failure::format_err!(
+            "The key \"{}\" can't be combined with ctrl/alt/shift; only a single character is supported",
+            base
+        ));
+    }
+    Ok(get_single_char(base))
+}
+
+fn parse_key(key: String) -> Result<KeyBinding, failure::Error> {
+    if key.len() == 1 {
+        return Ok(KeyBinding::Single(Key::Char(get_single_char(&key))));
+    }
+
+    let sections: Vec<&str> = key.split('-').collect();
+
+    if sections.len() == 1 {
+        return Ok(KeyBinding::Single(parse_named_key(sections[0])?));
+    }
+
+    let modifier_count = sections[..sections.len() - 1]
+        .iter()
+        .take_while(|s| MODIFIERS.contains(&s.to_lowercase().as_str()))
+        .count();
+
+    // Every token but the last is a recognized modifier: fold them onto the
+    // final token to produce a single key, e.g. `ctrl-j`, `shift-tab`.
+    if modifier_count > 0 && modifier_count == sections.len() - 1 {
+        let modifiers: Vec<String> = sections[..modifier_count]
+            .iter()
+            .map(|s| s.to_lowercase())
+            .collect();
+        let base = sections[sections.len() - 1];
+        return Ok(KeyBinding::Single(apply_modifiers(&modifiers, base)?));
+    }
+
+    // At least one leading token is itself a key, not a modifier: treat the
+    // whole binding as a chord of sequential presses, e.g. `g-g`.
+    let keys = sections
+        .iter()
+        .map(|s| parse_named_key(s))
+        .collect::<Result<Vec<Key>, failure::Error>>()?;
+    Ok(KeyBinding::Sequence(keys))
+}
+
+fn parse_color(s: &str) -> Result<Color, failure::Error> {
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(failure::format_err!(
+                "The color \"{}\" is invalid, expected a 6 digit hex code",
+                s
+            ));
+        }
+        let r = u8::from_str_radix(&hex[0..2], 16)?;
+        let g = u8::from_str_radix(&hex[2..4], 16)?;
+        let b = u8::from_str_radix(&hex[4..6], 16)?;
+        return Ok(Color::Rgb(r, g, b));
+    }
+
+    match s.to_lowercase().as_str() {
+        "black" => Ok(Color::Black),
+        "red" => Ok(Color::Red),
+        "green" => Ok(Color::Green),
+        "yellow" => Ok(Color::Yellow),
+        "blue" => Ok(Color::Blue),
+        "magenta" => Ok(Color::Magenta),
+        "cyan" => Ok(Color::Cyan),
+        "gray" | "grey" => Ok(Color::Gray),
+        "darkgray" | "darkgrey" => Ok(Color::DarkGray),
+        "lightred" => Ok(Color::LightRed),
+        "lightgreen" => Ok(Color::LightGreen),
+        "lightyellow" => Ok(Color::LightYellow),
+        "lightblue" => Ok(Color::LightBlue),
+        "lightmagenta" => Ok(Color::LightMagenta),
+        "lightcyan" => Ok(Color::LightCyan),
+        "white" => Ok(Color::White),
+        "reset" => Ok(Color::Reset),
+        _ => Err(failure::format_err!("The color \"{}\" is unknown.", s)),
+    }
+}
+
+// Pretty-prints a `Key` the way a user would type it in config.yml, e.g.
+// `Ctrl-j` instead of termion's `Ctrl('j')`.
+fn format_key(key: Key) -> String {
+    match key {
+        Key::Char('\n') => "Enter".to_string(),
+        Key::Char(' ') => "Space".to_string(),
+        Key::Char(c) => c.to_string(),
+        Key::Ctrl(c) => format!("Ctrl-{}", c),
+        Key::Alt(c) => format!("Alt-{}", c),
+        Key::F(n) => format!("F{}", n),
+        Key::Left => "Left".to_string(),
+        Key::Right => "Right".to_string(),
+        Key::Up => "Up".to_string(),
+        Key::Down => "Down".to_string(),
+        Key::Backspace => "Backspace".to_string(),
+        Key::Delete => "Delete".to_string(),
+        Key::Esc => "Esc".to_string(),
+        Key::PageUp => "PageUp".to_string(),
+        Key::PageDown => "PageDown".to_string(),
+        Key::BackTab => "Shift-Tab".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyBinding::Single(key) => write!(f, "{}", format_key(*key)),
+            KeyBinding::Sequence(keys) => {
+                let parts: Vec<String> = keys.iter().map(|key| format_key(*key)).collect();
+                write!(f, "{}", parts.join("-"))
             }
         }
     }
 }
 
-fn check_reserved_keys(key: Key) -> Result<(), failure::Error> {
+// Two bindings conflict if they're identical, or if one is a `Single` key
+// that is also the leading key of the other's `Sequence` chord - in that
+// case `App::dispatch_key` acts on whichever of the two comes first in its
+// binding order (firing the `Single`, or buffering the press for the chord),
+// so the other binding could never fire.
+fn bindings_conflict(a: &KeyBinding, b: &KeyBinding) -> bool {
+    match (a, b) {
+        (KeyBinding::Single(a), KeyBinding::Single(b)) => a == b,
+        (KeyBinding::Sequence(a), KeyBinding::Sequence(b)) => a == b,
+        (KeyBinding::Single(key), KeyBinding::Sequence(chord))
+        | (KeyBinding::Sequence(chord), KeyBinding::Single(key)) => chord.first() == Some(key),
+    }
+}
+
+fn check_reserved_keys(binding: &KeyBinding) -> Result<(), failure::Error> {
+    let key = match binding {
+        KeyBinding::Single(key) => *key,
+        // Chords require two presses in a row, so they can't shadow the
+        // single-press reserved keys below.
+        KeyBinding::Sequence(_) => return Ok(()),
+    };
     let reserved = [
         Key::Char('h'),
         Key::Char('j'),
@@ -67,10 +243,9 @@ fn check_reserved_keys(key: Key) -> Result<(), failure::Error> {
     ];
     for item in reserved.iter() {
         if key == *item {
-            // TODO: Add pretty print for key
             return Err(failure::format_err!(
-                "The key {:?} is reserved and cannot be remapped",
-                key
+                "The key \"{}\" is reserved and cannot be remapped",
+                format_key(key)
             ));
         }
     }
@@ -94,24 +269,88 @@ pub struct UserConfigString {
     shuffle: Option<String>,
     repeat: Option<String>,
     search: Option<String>,
+    theme: Option<ThemeConfig>,
+    lastfm: Option<LastfmConfig>,
+    behavior: Option<BehaviorConfig>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BehaviorConfig {
+    volume_increment: Option<u8>,
+    seek_milliseconds: Option<u32>,
+    tick_rate_milliseconds: Option<u64>,
+    default_device_name: Option<String>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LastfmConfig {
+    username: Option<String>,
+    password: Option<String>,
+    api_key: Option<String>,
+    api_secret: Option<String>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    active: Option<String>,
+    inactive: Option<String>,
+    hovered: Option<String>,
+    text: Option<String>,
+    header: Option<String>,
+    playbar_background: Option<String>,
+    playbar_progress: Option<String>,
+    banner: Option<String>,
+    selected: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub active: Color,
+    pub inactive: Color,
+    pub hovered: Color,
+    pub text: Color,
+    pub header: Color,
+    pub playbar_background: Color,
+    pub playbar_progress: Color,
+    pub banner: Color,
+    pub selected: Color,
 }
 
 pub struct UserConfig {
-    pub back: Key,
-    pub jump_to_album: Key,
-    pub jump_to_artist_album: Key,
-    pub manage_devices: Key,
-    pub decrease_volume: Key,
-    pub increase_volume: Key,
-    pub toggle_playback: Key,
-    pub seek_backwards: Key,
-    pub seek_forwards: Key,
-    pub next_track: Key,
-    pub previous_track: Key,
-    pub help: Key,
-    pub shuffle: Key,
-    pub repeat: Key,
-    pub search: Key,
+    pub back: KeyBinding,
+    pub jump_to_album: KeyBinding,
+    pub jump_to_artist_album: KeyBinding,
+    pub manage_devices: KeyBinding,
+    pub decrease_volume: KeyBinding,
+    pub increase_volume: KeyBinding,
+    pub toggle_playback: KeyBinding,
+    pub seek_backwards: KeyBinding,
+    pub seek_forwards: KeyBinding,
+    pub next_track: KeyBinding,
+    pub previous_track: KeyBinding,
+    pub help: KeyBinding,
+    pub shuffle: KeyBinding,
+    pub repeat: KeyBinding,
+    pub search: KeyBinding,
+    pub theme: Theme,
+    pub lastfm: Option<LastfmClientConfig>,
+    pub behavior: Behavior,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LastfmClientConfig {
+    pub username: String,
+    pub password: String,
+    pub api_key: String,
+    pub api_secret: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Behavior {
+    pub volume_increment: u8,
+    pub seek_milliseconds: u32,
+    pub tick_rate_milliseconds: u64,
+    pub default_device_name: Option<String>,
 }
 
 pub struct UserConfigPaths {
@@ -121,53 +360,86 @@ pub struct UserConfigPaths {
 impl UserConfig {
     pub fn new() -> UserConfig {
         UserConfig {
-            back: Key::Char('q'),
-            jump_to_album: Key::Char('a'),
-            jump_to_artist_album: Key::Char('A'),
-            manage_devices: Key::Char('d'),
-            decrease_volume: Key::Char('-'),
-            increase_volume: Key::Char('+'),
-            toggle_playback: Key::Char(' '),
-            seek_backwards: Key::Char('<'),
-            seek_forwards: Key::Char('>'),
-            next_track: Key::Char('n'),
-            previous_track: Key::Char('p'),
-            help: Key::Char('?'),
-            shuffle: Key::Char('s'),
-            repeat: Key::Char('r'),
-            search: Key::Char('/'),
+            back: KeyBinding::Single(Key::Char('q')),
+            jump_to_album: KeyBinding::Single(Key::Char('a')),
+            jump_to_artist_album: KeyBinding::Single(Key::Char('A')),
+            manage_devices: KeyBinding::Single(Key::Char('d')),
+            decrease_volume: KeyBinding::Single(Key::Char('-')),
+            increase_volume: KeyBinding::Single(Key::Char('+')),
+            toggle_playback: KeyBinding::Single(Key::Char(' ')),
+            seek_backwards: KeyBinding::Single(Key::Char('<')),
+            seek_forwards: KeyBinding::Single(Key::Char('>')),
+            next_track: KeyBinding::Single(Key::Char('n')),
+            previous_track: KeyBinding::Single(Key::Char('p')),
+            help: KeyBinding::Single(Key::Char('?')),
+            shuffle: KeyBinding::Single(Key::Char('s')),
+            repeat: KeyBinding::Single(Key::Char('r')),
+            search: KeyBinding::Single(Key::Char('/')),
+            theme: Theme {
+                active: Color::Cyan,
+                inactive: Color::Gray,
+                hovered: Color::Magenta,
+                text: Color::White,
+                header: Color::Cyan,
+                playbar_background: Color::Black,
+                playbar_progress: Color::Green,
+                banner: Color::LightCyan,
+                selected: Color::LightCyan,
+            },
+            lastfm: None,
+            behavior: Behavior {
+                volume_increment: 10,
+                seek_milliseconds: 5_000,
+                tick_rate_milliseconds: 250,
+                default_device_name: None,
+            },
         }
     }
 
-    pub fn get_or_build_paths(&self) -> Result<(UserConfigPaths), failure::Error> {
-        match dirs::home_dir() {
-            Some(home) => {
-                let path = Path::new(&home);
-                let home_config_dir = path.join(CONFIG_DIR);
-                let app_config_dir = home_config_dir.join(APP_CONFIG_DIR);
-
-                if !home_config_dir.exists() {
-                    fs::create_dir(&home_config_dir)?;
+    // Resolves the config file path, honouring (in order of preference) an
+    // explicit override (the `--config <FILE>` CLI flag), `$XDG_CONFIG_HOME`,
+    // and finally `~/.config`, creating any missing parent directories.
+    pub fn get_or_build_paths(
+        &self,
+        config_file_path: Option<PathBuf>,
+    ) -> Result<(UserConfigPaths), failure::Error> {
+        if let Some(config_file_path) = config_file_path {
+            if let Some(parent) = config_file_path.parent() {
+                if !parent.as_os_str().is_empty() && !parent.exists() {
+                    fs::create_dir_all(parent)?;
                 }
+            }
 
-                if !app_config_dir.exists() {
-                    fs::create_dir(&app_config_dir)?;
-                }
+            return Ok(UserConfigPaths { config_file_path });
+        }
 
-                let config_file_path = &app_config_dir.join(FILE_NAME);
+        let home_config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(xdg_config_home) => PathBuf::from(xdg_config_home),
+            None => match dirs::home_dir() {
+                Some(home) => Path::new(&home).join(CONFIG_DIR),
+                None => return Err(err_msg("No $HOME directory found for client config")),
+            },
+        };
 
-                let paths = UserConfigPaths {
-                    config_file_path: config_file_path.to_path_buf(),
-                };
+        let app_config_dir = home_config_dir.join(APP_CONFIG_DIR);
 
-                Ok(paths)
-            }
-            None => Err(err_msg("No $HOME directory found for client config")),
+        if !home_config_dir.exists() {
+            fs::create_dir_all(&home_config_dir)?;
         }
+
+        if !app_config_dir.exists() {
+            fs::create_dir(&app_config_dir)?;
+        }
+
+        let config_file_path = app_config_dir.join(FILE_NAME);
+
+        Ok(UserConfigPaths { config_file_path })
     }
 
-    pub fn load_config(&mut self) -> Result<(), failure::Error> {
-        let paths = self.get_or_build_paths()?;
+    // `config_file_path` is `Some` when the user passed `--config <FILE>` on
+    // the command line, overriding the default XDG-resolved location.
+    pub fn load_config(&mut self, config_file_path: Option<PathBuf>) -> Result<(), failure::Error> {
+        let paths = self.get_or_build_paths(config_file_path)?;
         if paths.config_file_path.exists() {
             let config_string = fs::read_to_string(&paths.config_file_path)?;
             // serde fails if file is empty
@@ -180,7 +452,7 @@ impl UserConfig {
                 ($name: ident) => {
                     if let Some(key_string) = config_yml.$name {
                         self.$name = parse_key(key_string)?;
-                        check_reserved_keys(self.$name)?;
+                        check_reserved_keys(&self.$name)?;
                     }
                 };
             };
@@ -201,6 +473,117 @@ impl UserConfig {
             to_keys!(repeat);
             to_keys!(search);
 
+            let mut bound_keys: Vec<(KeyBinding, &'static str)> = Vec::new();
+            macro_rules! check_conflicts {
+                ($name: ident) => {
+                    if let Some((_, action)) = bound_keys
+                        .iter()
+                        .find(|(binding, _)| bindings_conflict(binding, &self.$name))
+                    {
+                        return Err(failure::format_err!(
+                            "\"{}\" and \"{}\" are both bound to \"{}\"",
+                            action,
+                            stringify!($name),
+                            self.$name
+                        ));
+                    }
+                    bound_keys.push((self.$name.clone(), stringify!($name)));
+                };
+            };
+
+            check_conflicts!(back);
+            check_conflicts!(jump_to_album);
+            check_conflicts!(jump_to_artist_album);
+            check_conflicts!(manage_devices);
+            check_conflicts!(decrease_volume);
+            check_conflicts!(increase_volume);
+            check_conflicts!(toggle_playback);
+            check_conflicts!(seek_backwards);
+            check_conflicts!(seek_forwards);
+            check_conflicts!(next_track);
+            check_conflicts!(previous_track);
+            check_conflicts!(help);
+            check_conflicts!(shuffle);
+            check_conflicts!(repeat);
+            check_conflicts!(search);
+
+            if let Some(theme) = config_yml.theme {
+                macro_rules! to_theme_item {
+                    ($name: ident) => {
+                        if let Some(color_string) = theme.$name {
+                            self.theme.$name = parse_color(&color_string)?;
+                        }
+                    };
+                };
+
+                to_theme_item!(active);
+                to_theme_item!(inactive);
+                to_theme_item!(hovered);
+                to_theme_item!(text);
+                to_theme_item!(header);
+                to_theme_item!(playbar_background);
+                to_theme_item!(playbar_progress);
+                to_theme_item!(banner);
+                to_theme_item!(selected);
+            }
+
+            if let Some(lastfm) = config_yml.lastfm {
+                let username = lastfm
+                    .username
+                    .ok_or_else(|| failure::format_err!("lastfm.username is required"))?;
+                let password = lastfm
+                    .password
+                    .ok_or_else(|| failure::format_err!("lastfm.password is required"))?;
+                let api_key = lastfm
+                    .api_key
+                    .or_else(|| std::env::var(LASTFM_API_KEY_ENV).ok())
+                    .ok_or_else(|| {
+                        failure::format_err!(
+                            "lastfm.api_key is missing from config.yml and {} is not set",
+                            LASTFM_API_KEY_ENV
+                        )
+                    })?;
+                let api_secret = lastfm
+                    .api_secret
+                    .ok_or_else(|| failure::format_err!("lastfm.api_secret is required"))?;
+
+                self.lastfm = Some(LastfmClientConfig {
+                    username,
+                    password,
+                    api_key,
+                    api_secret,
+                });
+            }
+
+            if let Some(behavior) = config_yml.behavior {
+                if let Some(volume_increment) = behavior.volume_increment {
+                    if volume_increment == 0 || volume_increment > 100 {
+                        return Err(failure::format_err!(
+                            "behavior.volume_increment must be between 1 and 100, got {}",
+                            volume_increment
+                        ));
+                    }
+                    self.behavior.volume_increment = volume_increment;
+                }
+
+                if let Some(seek_milliseconds) = behavior.seek_milliseconds {
+                    self.behavior.seek_milliseconds = seek_milliseconds;
+                }
+
+                if let Some(tick_rate_milliseconds) = behavior.tick_rate_milliseconds {
+                    if tick_rate_milliseconds == 0 {
+                        return Err(failure::format_err!(
+                            "behavior.tick_rate_milliseconds must be greater than 0"
+                        ));
+                    }
+                    self.behavior.tick_rate_milliseconds = tick_rate_milliseconds;
+                }
+
+                if let Some(default_device_name) = behavior.default_device_name {
+                    self.behavior.default_device_name = Some(default_device_name);
+                }
+            }
+
             Ok(())
         } else {
             Ok(())
@@ -212,25 +595,122 @@ impl UserConfig {
 mod tests {
     #[test]
     fn test_parse_key() {
+        use super::{parse_key, KeyBinding};
+        use termion::event::Key;
+        assert_eq!(
+            parse_key(String::from("j")).unwrap(),
+            KeyBinding::Single(Key::Char('j'))
+        );
+        assert_eq!(
+            parse_key(String::from("J")).unwrap(),
+            KeyBinding::Single(Key::Char('J'))
+        );
+        assert_eq!(
+            parse_key(String::from("ctrl-j")).unwrap(),
+            KeyBinding::Single(Key::Ctrl('j'))
+        );
+        assert_eq!(
+            parse_key(String::from("ctrl-J")).unwrap(),
+            KeyBinding::Single(Key::Ctrl('J'))
+        );
+        assert_eq!(
+            parse_key(String::from("-")).unwrap(),
+            KeyBinding::Single(Key::Char('-'))
+        );
+        assert_eq!(
+            parse_key(String::from("esc")).unwrap(),
+            KeyBinding::Single(Key::Esc)
+        );
+        assert_eq!(
+            parse_key(String::from("del")).unwrap(),
+            KeyBinding::Single(Key::Delete)
+        );
+    }
+
+    #[test]
+    fn test_parse_key_function_and_modifiers() {
+        use super::{parse_key, KeyBinding};
+        use termion::event::Key;
+        assert_eq!(
+            parse_key(String::from("f5")).unwrap(),
+            KeyBinding::Single(Key::F(5))
+        );
+        assert_eq!(
+            parse_key(String::from("shift-tab")).unwrap(),
+            KeyBinding::Single(Key::BackTab)
+        );
+        assert_eq!(
+            parse_key(String::from("shift-j")).unwrap(),
+            KeyBinding::Single(Key::Char('J'))
+        );
+        assert!(parse_key(String::from("ctrl-alt-x")).is_err());
+    }
+
+    #[test]
+    fn test_parse_key_rejects_named_base_under_modifier() {
         use super::parse_key;
+        assert!(parse_key(String::from("ctrl-left")).is_err());
+        assert!(parse_key(String::from("alt-space")).is_err());
+    }
+
+    #[test]
+    fn test_parse_key_chord() {
+        use super::{parse_key, KeyBinding};
         use termion::event::Key;
-        assert_eq!(parse_key(String::from("j")).unwrap(), Key::Char('j'));
-        assert_eq!(parse_key(String::from("J")).unwrap(), Key::Char('J'));
-        assert_eq!(parse_key(String::from("ctrl-j")).unwrap(), Key::Ctrl('j'));
-        assert_eq!(parse_key(String::from("ctrl-J")).unwrap(), Key::Ctrl('J'));
-        assert_eq!(parse_key(String::from("-")).unwrap(), Key::Char('-'));
-        assert_eq!(parse_key(String::from("esc")).unwrap(), Key::Esc);
-        assert_eq!(parse_key(String::from("del")).unwrap(), Key::Delete);
+        assert_eq!(
+            parse_key(String::from("g-g")).unwrap(),
+            KeyBinding::Sequence(vec![Key::Char('g'), Key::Char('g')])
+        );
+    }
+
+    #[test]
+    fn test_parse_color() {
+        use super::parse_color;
+        use tui::style::Color;
+        assert_eq!(parse_color("Red").unwrap(), Color::Red);
+        assert_eq!(parse_color("#ff00ff").unwrap(), Color::Rgb(255, 0, 255));
+        assert!(parse_color("notacolor").is_err());
+        assert!(parse_color("#fff").is_err());
+        // A non-ASCII 6-byte string isn't a valid hex code, but would panic
+        // on a naive `&hex[0..2]` byte slice since 'é' isn't on a char
+        // boundary - this must error instead of panicking.
+        assert!(parse_color("#aé234").is_err());
+    }
+
+    #[test]
+    fn test_bindings_conflict_detects_chord_prefix() {
+        use super::{bindings_conflict, KeyBinding};
+        use termion::event::Key;
+
+        let single = KeyBinding::Single(Key::Char('g'));
+        let chord = KeyBinding::Sequence(vec![Key::Char('g'), Key::Char('g')]);
+        assert!(bindings_conflict(&single, &chord));
+        assert!(bindings_conflict(&chord, &single));
+
+        let other_chord = KeyBinding::Sequence(vec![Key::Char('x'), Key::Char('g')]);
+        assert!(!bindings_conflict(&single, &other_chord));
     }
 
     #[test]
     fn test_reserved_key() {
-        use super::check_reserved_keys;
+        use super::{check_reserved_keys, KeyBinding};
         use termion::event::Key;
 
         assert!(
-            check_reserved_keys(&Key::Char('\n')).is_err(),
+            check_reserved_keys(&KeyBinding::Single(Key::Char('\n'))).is_err(),
             "Enter key should be reserved"
         );
     }
+
+    #[test]
+    fn test_key_binding_display() {
+        use super::KeyBinding;
+        use termion::event::Key;
+
+        assert_eq!(KeyBinding::Single(Key::Ctrl('j')).to_string(), "Ctrl-j");
+        assert_eq!(
+            KeyBinding::Sequence(vec![Key::Char('g'), Key::Char('g')]).to_string(),
+            "g-g"
+        );
+    }
 }